@@ -6,27 +6,99 @@
 )]
 
 use std::{
+    collections::VecDeque,
     env,
-    process::{Child, Command, Stdio},
-    sync::{Arc, Mutex},
-    time::Duration,
+    io::{BufRead, BufReader, Read},
+    net::{SocketAddr, TcpListener, TcpStream},
+    path::PathBuf,
+    process::{Command, Stdio},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
 };
+use shared_child::SharedChild;
 use tauri::{Manager, WindowEvent};
 
+// Default bound on how long we wait for the Python server to accept
+// connections before giving up; overridable via `HBD_STARTUP_TIMEOUT` (secs).
+const DEFAULT_STARTUP_TIMEOUT_SECS: u64 = 15;
+// How often the readiness probe retries the TCP connect.
+const PROBE_INTERVAL: Duration = Duration::from_millis(100);
+// Crash-loop guard: at most this many restarts inside `RESTART_WINDOW`.
+const MAX_RESTARTS: u32 = 5;
+const RESTART_WINDOW: Duration = Duration::from_secs(60);
+// Backoff between restart attempts grows from this base, capped.
+const BACKOFF_BASE: Duration = Duration::from_millis(250);
+const BACKOFF_MAX: Duration = Duration::from_secs(10);
+// How long we wait for a polite SIGTERM before escalating to SIGKILL (Unix).
+const TERM_GRACE: Duration = Duration::from_secs(5);
+// How many lines of backend output to retain in memory for error dialogs.
+const LOG_TAIL_LINES: usize = 200;
+
+/// A spawned backend process plus whatever OS handle keeps its descendants
+/// reapable. On Windows the `job` keeps every grandchild bound to a Job Object
+/// that is killed when closed; on Unix the child leads its own process group.
+#[derive(Clone)]
+struct Running {
+    child: Arc<SharedChild>,
+    #[cfg(windows)]
+    job: Arc<job::Job>,
+}
+
+/// Backend status reported over IPC and carried by `server-ready` events.
+#[derive(Clone, serde::Serialize)]
+struct ServerStatus {
+    running: bool,
+    port: u16,
+    pid: Option<u32>,
+    restarts: u32,
+}
+
 #[derive(Clone)]
-struct ServerState(Arc<Mutex<Option<Child>>>);
+struct ServerState {
+    child: Arc<Mutex<Option<Running>>>,
+    port: u16,
+    // Bumped by `stop()` to retire the current supervisor: a monitor thread
+    // only acts while its captured generation still matches, so an intentional
+    // stop/restart can never be mistaken for a crash by a stale supervisor.
+    generation: Arc<AtomicU32>,
+    // Total successful restarts, surfaced to the frontend via `status()`.
+    restarts: Arc<AtomicU32>,
+    // Ring buffer of the most recent backend output lines, for error dialogs.
+    log_tail: Arc<Mutex<VecDeque<String>>>,
+}
 
 impl ServerState {
     fn new() -> Self {
-        Self(Arc::new(Mutex::new(None)))
+        // Honour an explicit port, otherwise grab a free ephemeral one so a
+        // second launch (or a leftover orphan) never collides on 8000. A set
+        // but unparseable value is an operator mistake, so warn rather than
+        // silently loading a different port than was configured.
+        let port = match env::var("HBD_UI_PORT") {
+            Ok(value) => value.parse::<u16>().unwrap_or_else(|_| {
+                let fallback = allocate_port();
+                log::warn!("Ignoring invalid HBD_UI_PORT={value:?}; using port {fallback}");
+                fallback
+            }),
+            Err(_) => allocate_port(),
+        };
+        Self {
+            child: Arc::new(Mutex::new(None)),
+            port,
+            generation: Arc::new(AtomicU32::new(0)),
+            restarts: Arc::new(AtomicU32::new(0)),
+            log_tail: Arc::new(Mutex::new(VecDeque::with_capacity(LOG_TAIL_LINES))),
+        }
     }
 
     fn start(&self) -> Result<(), String> {
-        let mut guard = self.0.lock().map_err(|e| e.to_string())?;
+        let mut guard = self.child.lock().map_err(|e| e.to_string())?;
         if guard.is_some() {
             return Ok(());
         }
-        let port = env::var("HBD_UI_PORT").unwrap_or_else(|_| "8000".to_string());
+        let port = self.port.to_string();
         // Prefer bundled venv python, allow override; bootstrap if missing.
         let mut python = env::var("HBD_PYTHON").ok().filter(|s| !s.is_empty());
         if python.is_none() {
@@ -58,35 +130,527 @@ impl ServerState {
             .env("PYTHONUNBUFFERED", "1")
             // Ensure the module is importable.
             .env("PYTHONPATH", merged_pythonpath)
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit());
-        let child = cmd.spawn().map_err(|e| format!("Failed to start UI server: {e}"))?;
-        *guard = Some(child);
+            // Capture output so it survives `windows_subsystem = "windows"`.
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        // Set up process-tree containment *before* the child is resumed so no
+        // uvicorn worker can escape it (new process group on Unix, suspended
+        // start on Windows).
+        configure_process_tree(&mut cmd);
+        let child = Arc::new(
+            SharedChild::spawn(&mut cmd).map_err(|e| format!("Failed to start UI server: {e}"))?,
+        );
+        if let Some(out) = child.take_stdout() {
+            self.forward_output(out, false);
+        }
+        if let Some(err) = child.take_stderr() {
+            self.forward_output(err, true);
+        }
+        let running = adopt_process_tree(child)?;
+        *guard = Some(running);
         Ok(())
     }
 
+    /// Pump a backend stream line-by-line into the `log` crate and the tail
+    /// ring buffer. stderr is logged at error level, stdout at info level.
+    fn forward_output<R: Read + Send + 'static>(&self, reader: R, is_err: bool) {
+        let tail = self.log_tail.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(reader).lines() {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(_) => break,
+                };
+                if is_err {
+                    log::error!(target: "ui_server", "{line}");
+                } else {
+                    log::info!(target: "ui_server", "{line}");
+                }
+                if let Ok(mut tail) = tail.lock() {
+                    if tail.len() == LOG_TAIL_LINES {
+                        tail.pop_front();
+                    }
+                    tail.push_back(line);
+                }
+            }
+        });
+    }
+
+    /// A snapshot of the current backend status for the frontend.
+    fn status(&self) -> ServerStatus {
+        let (running, pid) = self
+            .child
+            .lock()
+            .ok()
+            .and_then(|g| g.as_ref().map(|r| (true, Some(r.child.id()))))
+            .unwrap_or((false, None));
+        ServerStatus {
+            running,
+            port: self.port,
+            pid,
+            restarts: self.restarts.load(Ordering::SeqCst),
+        }
+    }
+
+    /// The most recent `n` lines of backend output, oldest first.
+    fn recent_logs(&self, n: usize) -> String {
+        self.log_tail
+            .lock()
+            .map(|tail| {
+                let skip = tail.len().saturating_sub(n);
+                tail.iter().skip(skip).cloned().collect::<Vec<_>>().join("\n")
+            })
+            .unwrap_or_default()
+    }
+
+    /// Block until the server accepts a TCP connection on its port, or fail.
+    ///
+    /// Retries a short-timeout `connect` every `PROBE_INTERVAL` until the
+    /// deadline. On every iteration we also `try_wait()` the child so that a
+    /// Python process that dies during startup is reported immediately instead
+    /// of stalling for the full timeout.
+    fn wait_until_ready(&self, timeout: Duration) -> Result<(), String> {
+        let addr: SocketAddr = ([127, 0, 0, 1], self.port).into();
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Ok(guard) = self.child.lock() {
+                if let Some(running) = guard.as_ref() {
+                    if let Ok(Some(status)) = running.child.try_wait() {
+                        return Err(format!(
+                            "UI server exited during startup with status {status}"
+                        ));
+                    }
+                }
+            }
+            if TcpStream::connect_timeout(&addr, PROBE_INTERVAL).is_ok() {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(format!(
+                    "UI server did not become ready within {} seconds",
+                    timeout.as_secs()
+                ));
+            }
+            std::thread::sleep(PROBE_INTERVAL);
+        }
+    }
+
     fn stop(&self) {
-        if let Ok(mut guard) = self.0.lock() {
-            if let Some(child) = guard.as_mut() {
-                let _ = child.kill();
-                let _ = child.wait();
+        // Retire the current supervisor first: bumping the generation before we
+        // reap the child means the monitor observes the new value when its
+        // `wait()` unblocks and exits instead of treating this as a crash.
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        if let Ok(mut guard) = self.child.lock() {
+            if let Some(running) = guard.as_ref() {
+                terminate_process_tree(running);
             }
             *guard = None;
         }
     }
+
+    /// Spawn a monitor thread that blocks on the child and recovers from
+    /// crashes. The monitor captures the current generation on startup and
+    /// stops the moment it no longer matches (an intentional `stop()`/restart),
+    /// so it never races a fresh child. Any other exit is treated as a crash:
+    /// the server is restarted with exponential backoff, and more than
+    /// `MAX_RESTARTS` crashes within the rolling `RESTART_WINDOW` emit
+    /// `server-crashed` to the frontend and surface a dialog.
+    fn supervise(&self, app: tauri::AppHandle) {
+        let state = self.clone();
+        let my_generation = self.generation.load(Ordering::SeqCst);
+        std::thread::spawn(move || {
+            // Crash timestamps inside the rolling `RESTART_WINDOW`.
+            let mut crashes: VecDeque<Instant> = VecDeque::new();
+            loop {
+                let child = match state
+                    .child
+                    .lock()
+                    .ok()
+                    .and_then(|g| g.as_ref().map(|r| r.child.clone()))
+                {
+                    Some(child) => child,
+                    None => return,
+                };
+                let status = child.wait();
+                // A generation bump means this supervisor has been retired.
+                if state.generation.load(Ordering::SeqCst) != my_generation {
+                    return;
+                }
+
+                let now = Instant::now();
+                crashes.push_back(now);
+                while crashes
+                    .front()
+                    .is_some_and(|&t| now.duration_since(t) > RESTART_WINDOW)
+                {
+                    crashes.pop_front();
+                }
+                let attempts = crashes.len() as u32;
+                let detail = match status {
+                    Ok(s) => format!("exited with status {s}"),
+                    Err(e) => format!("could not be waited on: {e}"),
+                };
+                log::warn!("UI server {detail}; restart attempt {attempts}");
+
+                if attempts > MAX_RESTARTS {
+                    // Reap the crashed tree before giving up so no orphaned
+                    // uvicorn worker outlives the app holding the port.
+                    if let Ok(mut guard) = state.child.lock() {
+                        if let Some(running) = guard.as_ref() {
+                            terminate_process_tree(running);
+                        }
+                        *guard = None;
+                    }
+                    let _ = app.emit_all("server-crashed", attempts);
+                    error_dialog(with_log_tail(
+                        format!("The embedded UI server keeps crashing ({detail}).\n\nGiving up after {MAX_RESTARTS} restarts within {} seconds.", RESTART_WINDOW.as_secs()),
+                        &state.recent_logs(20),
+                    ));
+                    return;
+                }
+
+                let _ = app.emit_all("server-restarting", attempts);
+                std::thread::sleep(backoff_for(attempts));
+                // A stop()/restart_server() may have retired us during the
+                // backoff; re-check before touching `guard` so we never reap a
+                // freshly spawned child or resurrect a server the user closed.
+                if state.generation.load(Ordering::SeqCst) != my_generation {
+                    return;
+                }
+                // Reap any workers the dead parent left behind, then drop the
+                // handle so `start()` will spawn a fresh child.
+                if let Ok(mut guard) = state.child.lock() {
+                    if let Some(running) = guard.as_ref() {
+                        terminate_process_tree(running);
+                    }
+                    *guard = None;
+                }
+                if let Err(e) = state.start() {
+                    log::error!("Failed to restart UI server: {e}");
+                    // Leave the handle empty; the next loop iteration returns.
+                } else if state.generation.load(Ordering::SeqCst) != my_generation {
+                    // Retired while we were restarting: hand the just-spawned
+                    // child off to the current supervisor and bow out.
+                    return;
+                } else {
+                    state.restarts.fetch_add(1, Ordering::SeqCst);
+                    if state.wait_until_ready(startup_timeout()).is_ok() {
+                        let _ = app.emit_all("server-ready", state.status());
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// Reserve a free TCP port by binding `127.0.0.1:0` and reading back the
+/// number the OS assigned, then releasing it so the child can claim it. Falls
+/// back to the historical default if the probe bind fails.
+fn allocate_port() -> u16 {
+    TcpListener::bind((std::net::Ipv4Addr::LOCALHOST, 0))
+        .ok()
+        .and_then(|l| l.local_addr().ok())
+        .map(|addr| addr.port())
+        .unwrap_or(8000)
+}
+
+/// Readiness timeout, overridable via `HBD_STARTUP_TIMEOUT` (seconds).
+fn startup_timeout() -> Duration {
+    env::var("HBD_STARTUP_TIMEOUT")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .map(Duration::from_secs)
+        .unwrap_or_else(|| Duration::from_secs(DEFAULT_STARTUP_TIMEOUT_SECS))
+}
+
+/// Exponential backoff for restart attempt `n` (1-based), capped at `BACKOFF_MAX`.
+fn backoff_for(n: u32) -> Duration {
+    let shift = n.saturating_sub(1).min(16);
+    BACKOFF_BASE
+        .saturating_mul(1u32 << shift)
+        .min(BACKOFF_MAX)
+}
+
+/// Arrange for the child to anchor a killable process tree before it runs.
+#[cfg(unix)]
+fn configure_process_tree(cmd: &mut Command) {
+    use std::os::unix::process::CommandExt;
+    // Make the child a process-group leader (pgid == pid) so we can signal it
+    // and every uvicorn worker/reloader it spawns with a single `kill(-pgid)`.
+    cmd.process_group(0);
+}
+
+#[cfg(windows)]
+fn configure_process_tree(cmd: &mut Command) {
+    use std::os::windows::process::CommandExt;
+    // Start suspended so the Job Object can be attached before any grandchild
+    // is created; `adopt_process_tree` resumes the primary thread afterwards.
+    cmd.creation_flags(job::CREATE_SUSPENDED);
+}
+
+/// Finish wiring up tree containment once the child exists.
+#[cfg(unix)]
+fn adopt_process_tree(child: Arc<SharedChild>) -> Result<Running, String> {
+    Ok(Running { child })
+}
+
+#[cfg(windows)]
+fn adopt_process_tree(child: Arc<SharedChild>) -> Result<Running, String> {
+    let job = job::Job::new().map_err(|e| format!("Failed to create job object: {e}"))?;
+    job.assign(child.id())
+        .map_err(|e| format!("Failed to assign server to job object: {e}"))?;
+    // The child was spawned suspended; let it run now that it is contained.
+    job::resume_process(child.id()).map_err(|e| format!("Failed to resume server: {e}"))?;
+    Ok(Running {
+        child,
+        job: Arc::new(job),
+    })
+}
+
+/// Terminate the child and every descendant, then reap it.
+#[cfg(unix)]
+fn terminate_process_tree(running: &Running) {
+    let pgid = running.child.id() as i32;
+    // SIGTERM the whole group, give the leader a grace period to exit, then
+    // SIGKILL the group only if the leader is still alive.
+    unsafe { libc::kill(-pgid, libc::SIGTERM) };
+    if !wait_for_exit(&running.child, TERM_GRACE) {
+        unsafe { libc::kill(-pgid, libc::SIGKILL) };
+    }
+    let _ = running.child.wait();
+}
+
+#[cfg(windows)]
+fn terminate_process_tree(running: &Running) {
+    // Killing the job object tears down every process bound to it.
+    running.job.terminate();
+    let _ = running.child.wait();
+}
+
+/// Poll the child for up to `grace`, returning `true` if it exited in time.
+#[cfg(unix)]
+fn wait_for_exit(child: &SharedChild, grace: Duration) -> bool {
+    let deadline = Instant::now() + grace;
+    while Instant::now() < deadline {
+        if let Ok(Some(_)) = child.try_wait() {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    matches!(child.try_wait(), Ok(Some(_)))
+}
+
+/// Win32 Job Object wrapper used to kill the whole backend process tree.
+#[cfg(windows)]
+mod job {
+    use std::io;
+    use windows_sys::Win32::Foundation::{CloseHandle, HANDLE, INVALID_HANDLE_VALUE};
+    use windows_sys::Win32::System::Diagnostics::ToolHelp::{
+        CreateToolhelp32Snapshot, Thread32First, Thread32Next, TH32CS_SNAPTHREAD, THREADENTRY32,
+    };
+    use windows_sys::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, SetInformationJobObject, TerminateJobObject,
+        JobObjectExtendedLimitInformation, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+        JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+    };
+    use windows_sys::Win32::System::Threading::{
+        OpenProcess, OpenThread, ResumeThread, PROCESS_SET_QUOTA, PROCESS_TERMINATE,
+        THREAD_SUSPEND_RESUME,
+    };
+
+    pub const CREATE_SUSPENDED: u32 = 0x0000_0004;
+
+    pub struct Job(HANDLE);
+
+    // The handle is only ever touched behind `ServerState`'s mutex / via `Arc`.
+    unsafe impl Send for Job {}
+    unsafe impl Sync for Job {}
+
+    impl Job {
+        /// Create a job configured to kill all members when its last handle closes.
+        pub fn new() -> io::Result<Self> {
+            let handle = unsafe { CreateJobObjectW(std::ptr::null(), std::ptr::null()) };
+            if handle.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+            let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = unsafe { std::mem::zeroed() };
+            info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+            let ok = unsafe {
+                SetInformationJobObject(
+                    handle,
+                    JobObjectExtendedLimitInformation,
+                    &info as *const _ as *const _,
+                    std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                )
+            };
+            if ok == 0 {
+                let err = io::Error::last_os_error();
+                unsafe { CloseHandle(handle) };
+                return Err(err);
+            }
+            Ok(Self(handle))
+        }
+
+        /// Bind the process (and thus its future descendants) to this job.
+        pub fn assign(&self, pid: u32) -> io::Result<()> {
+            let proc = unsafe { OpenProcess(PROCESS_SET_QUOTA | PROCESS_TERMINATE, 0, pid) };
+            if proc.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+            let ok = unsafe { AssignProcessToJobObject(self.0, proc) };
+            unsafe { CloseHandle(proc) };
+            if ok == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        }
+
+        /// Forcibly terminate every process in the job.
+        pub fn terminate(&self) {
+            unsafe { TerminateJobObject(self.0, 1) };
+        }
+    }
+
+    impl Drop for Job {
+        fn drop(&mut self) {
+            // Closing the last handle kills the tree via KILL_ON_JOB_CLOSE.
+            unsafe { CloseHandle(self.0) };
+        }
+    }
+
+    /// Resume the primary (and any) threads of a process spawned suspended.
+    pub fn resume_process(pid: u32) -> io::Result<()> {
+        let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0) };
+        if snapshot == INVALID_HANDLE_VALUE {
+            return Err(io::Error::last_os_error());
+        }
+        let mut entry: THREADENTRY32 = unsafe { std::mem::zeroed() };
+        entry.dwSize = std::mem::size_of::<THREADENTRY32>() as u32;
+        let mut ok = unsafe { Thread32First(snapshot, &mut entry) };
+        while ok != 0 {
+            if entry.th32OwnerProcessID == pid {
+                let thread = unsafe { OpenThread(THREAD_SUSPEND_RESUME, 0, entry.th32ThreadID) };
+                if !thread.is_null() {
+                    unsafe { ResumeThread(thread) };
+                    unsafe { CloseHandle(thread) };
+                }
+            }
+            ok = unsafe { Thread32Next(snapshot, &mut entry) };
+        }
+        unsafe { CloseHandle(snapshot) };
+        Ok(())
+    }
+}
+
+/// Install a rotating file logger under the OS app-data log directory and wire
+/// the `log` crate to it. Verbosity defaults to `info` and is overridable via
+/// `HBD_LOG_LEVEL` (e.g. `debug`, `ui_server=trace`). Warnings and above are
+/// also duplicated to stderr for development runs with a console attached.
+fn init_logging() {
+    let level = env::var("HBD_LOG_LEVEL").unwrap_or_else(|_| "info".to_string());
+    let logger = flexi_logger::Logger::try_with_str(&level)
+        .or_else(|_| flexi_logger::Logger::try_with_str("info"));
+    if let Ok(logger) = logger {
+        let _ = logger
+            .log_to_file(
+                flexi_logger::FileSpec::default()
+                    .directory(log_directory())
+                    .basename("humble-control-center"),
+            )
+            .rotate(
+                flexi_logger::Criterion::Size(5 * 1024 * 1024),
+                flexi_logger::Naming::Timestamps,
+                flexi_logger::Cleanup::KeepLogFiles(5),
+            )
+            .duplicate_to_stderr(flexi_logger::Duplicate::Warn)
+            .start();
+    }
+}
+
+/// Directory for rotated log files, e.g. `~/.local/share/humble-control-center/logs`.
+fn log_directory() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(env::temp_dir)
+        .join("humble-control-center")
+        .join("logs")
+}
+
+/// Append the recent backend output (if any) to a dialog message body.
+fn with_log_tail(message: String, tail: &str) -> String {
+    if tail.is_empty() {
+        message
+    } else {
+        format!("{message}\n\n--- recent server output ---\n{tail}")
+    }
+}
+
+/// Show a blocking native error dialog on its own thread so the event loop is
+/// never blocked, returning once the user dismisses it.
+fn error_dialog(message: String) {
+    std::thread::spawn(move || {
+        tauri::api::dialog::blocking::MessageDialogBuilder::new(
+            "Humble Control Center",
+            message,
+        )
+        .kind(tauri::api::dialog::MessageDialogKind::Error)
+        .show();
+    })
+    .join()
+    .ok();
+}
+
+/// Like [`error_dialog`] but exits the process cleanly afterwards; used for
+/// unrecoverable startup failures.
+fn fatal_startup_error(message: String) -> ! {
+    error_dialog(message);
+    std::process::exit(1);
 }
 
 fn main() {
+    init_logging();
     tauri::Builder::default()
+        // Enforce a single instance keyed on the app identifier: a second
+        // launch hands its args to the primary and focuses its window instead
+        // of spawning a duplicate server.
+        .plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+            if let Some(window) = app.get_window("main") {
+                let _ = window.unminimize();
+                let _ = window.set_focus();
+            }
+        }))
         .manage(ServerState::new())
+        .invoke_handler(tauri::generate_handler![
+            server_status,
+            server_port,
+            get_server_logs,
+            restart_server
+        ])
         .setup(|app| {
             if bundled_python_path().is_none() {
                 bootstrap_venv();
             }
             let state = app.state::<ServerState>().clone();
             state.start()?;
-            // Give the server a brief head start before the window loads the URL.
-            std::thread::sleep(Duration::from_millis(300));
+            // Actively probe the server instead of racing a fixed sleep.
+            if let Err(e) = state.wait_until_ready(startup_timeout()) {
+                let tail = state.recent_logs(20);
+                state.stop();
+                fatal_startup_error(with_log_tail(
+                    format!("The embedded UI server failed to start.\n\n{e}"),
+                    &tail,
+                ));
+            }
+            // The backend is up; keep it up and tell the frontend.
+            state.supervise(app.handle());
+            // Point the WebView at the actual (possibly ephemeral) port rather
+            // than whatever static URL the window config may default to.
+            if let Some(window) = app.get_window("main") {
+                let _ = window.eval(&format!(
+                    "window.location.replace('http://127.0.0.1:{}/')",
+                    state.port
+                ));
+            }
+            let _ = app.emit_all("server-ready", state.status());
             Ok(())
         })
         .on_window_event(|event| {
@@ -99,6 +663,42 @@ fn main() {
         .expect("error while running Tauri application");
 }
 
+/// Current backend status: running flag, port, pid and restart count.
+#[tauri::command]
+fn server_status(state: tauri::State<ServerState>) -> ServerStatus {
+    state.status()
+}
+
+/// The port the embedded server is listening on.
+#[tauri::command]
+fn server_port(state: tauri::State<ServerState>) -> u16 {
+    state.port
+}
+
+/// The last `lines` lines of captured backend output.
+#[tauri::command]
+fn get_server_logs(lines: usize, state: tauri::State<ServerState>) -> String {
+    state.recent_logs(lines)
+}
+
+/// Restart the backend on demand, re-arming the supervisor and emitting the
+/// same lifecycle events a crash-recovery would.
+#[tauri::command]
+fn restart_server(app: tauri::AppHandle, state: tauri::State<ServerState>) -> Result<(), String> {
+    let _ = app.emit_all("server-restarting", ());
+    state.stop();
+    state.start()?;
+    // Count manual restarts alongside crash-recovery restarts so the tally
+    // reported by `server_status()` is consistent across both paths.
+    state.restarts.fetch_add(1, Ordering::SeqCst);
+    let timeout = startup_timeout();
+    state.wait_until_ready(timeout)?;
+    // `stop()` made the previous supervisor thread exit; start a fresh one.
+    state.supervise(app.clone());
+    let _ = app.emit_all("server-ready", state.status());
+    Ok(())
+}
+
 fn find_repo_root(start: &std::path::Path) -> Option<std::path::PathBuf> {
     let mut current = start.to_path_buf();
     for _ in 0..6 {
@@ -145,16 +745,62 @@ fn bootstrap_venv() {
     let ps1 = base.join("desktop").join("tauri-app").join("bootstrap.ps1");
     let sh = base.join("desktop").join("tauri-app").join("bootstrap.sh");
     if ps1.exists() {
-        let _ = Command::new("powershell")
-            .args(["-ExecutionPolicy", "Bypass", "-File", &ps1.to_string_lossy()])
+        let mut cmd = Command::new("powershell");
+        cmd.args(["-ExecutionPolicy", "Bypass", "-File", &ps1.to_string_lossy()])
             .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status();
+            .stderr(Stdio::inherit());
+        apply_proxy_env(&mut cmd);
+        let _ = cmd.status();
     } else if sh.exists() {
-        let _ = Command::new("bash")
-            .arg(sh.to_string_lossy().to_string())
+        let mut cmd = Command::new("bash");
+        cmd.arg(sh.to_string_lossy().to_string())
             .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status();
+            .stderr(Stdio::inherit());
+        apply_proxy_env(&mut cmd);
+        let _ = cmd.status();
     }
 }
+
+/// Forward proxy configuration to the bootstrap command so the initial
+/// `pip install` can reach the network on locked-down/corporate setups.
+///
+/// `HBD_PROXY` takes precedence over the ambient `HTTP_PROXY`/`HTTPS_PROXY`/
+/// `ALL_PROXY` variables; whatever value is chosen is also exported as
+/// `PIP_PROXY` so pip routes its downloads through it. `NO_PROXY` exclusions
+/// are always forwarded. SOCKS URLs (e.g. `socks5://host:1080`) are accepted
+/// and passed through verbatim.
+fn apply_proxy_env(cmd: &mut Command) {
+    // Honour both the upper- and lower-case spellings tools look for.
+    const PROXY_KEYS: [&str; 6] = [
+        "HTTP_PROXY", "http_proxy", "HTTPS_PROXY", "https_proxy", "ALL_PROXY", "all_proxy",
+    ];
+
+    if let Some(proxy) = env::var("HBD_PROXY").ok().filter(|s| !s.is_empty()) {
+        for key in PROXY_KEYS {
+            cmd.env(key, &proxy);
+        }
+        cmd.env("PIP_PROXY", &proxy);
+    } else {
+        for key in PROXY_KEYS {
+            if let Some(val) = env::var(key).ok().filter(|s| !s.is_empty()) {
+                cmd.env(key, val);
+            }
+        }
+        // Prefer the HTTPS proxy for pip, falling back to HTTP then ALL.
+        if let Some(proxy) = first_env(&["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy", "ALL_PROXY", "all_proxy"]) {
+            cmd.env("PIP_PROXY", proxy);
+        }
+    }
+
+    for key in ["NO_PROXY", "no_proxy"] {
+        if let Some(val) = env::var(key).ok().filter(|s| !s.is_empty()) {
+            cmd.env(key, val);
+        }
+    }
+}
+
+/// First non-empty value among the given environment variables, in order.
+fn first_env(keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .find_map(|key| env::var(key).ok().filter(|s| !s.is_empty()))
+}